@@ -0,0 +1,19 @@
+use mac_oui::Oui;
+use std::process::exit;
+
+fn main() {
+    let oui_db = match Oui::from_csv_file("assets/oui.csv") {
+        Ok(s) => s,
+        Err(e) => {
+            println!("{}", e);
+            exit(1)
+        }
+    };
+    match oui_db.to_binary("assets/oui.bin") {
+        Ok(()) => println!("wrote assets/oui.bin"),
+        Err(e) => {
+            println!("{}", e);
+            exit(1)
+        }
+    }
+}
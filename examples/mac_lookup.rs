@@ -1,8 +1,5 @@
-use mac_oui::Oui;
-use std::{
-    env,
-    process::exit
-};
+use mac_oui::{LookupResult, Oui};
+use std::{env, process::exit};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -21,13 +18,12 @@ fn main() {
     };
     let res = oui_db.lookup(&mac_addr);
     match res {
-        Ok(r) => {
-            if let Some(rec) = r {
-                println!("{:#?}", &rec)
-            } else {
-                println!("No entry found for: {}", mac_addr)
-            }
-        },
-        Err(e) => println!("Error: {}", e)
+        Ok(LookupResult::Found(rec)) => println!("{:#?}", &rec),
+        Ok(LookupResult::NotRegistered) => println!("No entry found for: {}", mac_addr),
+        Ok(LookupResult::LocallyAdministered(class)) => println!(
+            "{} is locally administered ({:?}); OUI lookup is not meaningful",
+            mac_addr, class
+        ),
+        Err(e) => println!("Error: {}", e),
     }
 }
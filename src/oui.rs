@@ -1,20 +1,40 @@
 use byteorder::{NetworkEndian, ReadBytesExt};
-use macaddr::MacAddr6 as MacAddress;
-use serde::{Deserialize, Deserializer, Serialize};
-use std::{collections::HashSet, fs::read_to_string, iter::FromIterator, path::Path};
+use macaddr::{MacAddr6 as MacAddress, MacAddr8};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::HashSet,
+    fs::{read_to_string, File},
+    io::{BufRead, BufReader, Read},
+    iter::FromIterator,
+    path::Path,
+};
 
 type Start = u64;
 type OuiMap = rangemap::RangeInclusiveMap<Start, Entry>;
 type OuiMultiMap = multimap::MultiMap<String, Entry>;
 type Error = String;
 
+/// Payload format accepted by [`Oui::from_reader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The macaddress.io CSV layout (the IEEE OUI download format).
+    Csv,
+    /// A JSON array of [`Entry`] objects.
+    Json,
+    /// Newline-delimited JSON, one [`Entry`] object per line.
+    NdJson,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all(deserialize = "camelCase"))]
 pub struct Entry {
     /// Organization Unique Identifier
     pub oui: String,
     /// flag is set to 'true' and companyName, companyAddress and countryCode are 'private'
-    #[serde(deserialize_with = "string_to_bool")]
+    #[serde(
+        deserialize_with = "string_to_bool",
+        serialize_with = "bool_to_string"
+    )]
     pub is_private: bool,
     /// Name of the company which registered the MAC addresses block
     pub company_name: String,
@@ -37,19 +57,134 @@ fn string_to_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s: &str = Deserialize::deserialize(deserializer)?;
-    match s {
+    // Owned `String` rather than `&str`: serde_json's reader-based
+    // deserializer can't hand out a borrowed string, so `&str` here would
+    // make every `Entry` fail to parse from `Oui::from_reader`.
+    let s: String = Deserialize::deserialize(deserializer)?;
+    match s.as_str() {
         "1" => Ok(true),
         _ => Ok(false),
     }
 }
 
+// Paired with `string_to_bool`: without a matching `serialize_with`, the
+// derived `Serialize` writes `is_private` as a plain bool while
+// `string_to_bool` only ever expects the "0"/"1" string form, so a
+// non-self-describing format like bincode (used by `Oui::to_binary`) would
+// desync and fail to read back what it just wrote.
+fn bool_to_string<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(if *value { "1" } else { "0" })
+}
+
+// `Serialize`/`Deserialize` here need `rangemap`'s `serde1` feature (for
+// `db: RangeInclusiveMap`) and `multimap`'s `serde` feature (for
+// `manufacturer_map: MultiMap`) enabled in Cargo.toml, or this derive
+// won't compile.
+#[derive(Serialize, Deserialize)]
 pub struct Oui {
     db: OuiMap,
     manufacturer_map: OuiMultiMap,
     manufacturers: HashSet<String>,
     ouis: HashSet<String>,
     records: i32,
+    /// `(normalized_name, original_name)` pairs used by `search_manufacturers`,
+    /// built once at load time instead of re-normalizing on every search.
+    normalized_manufacturers: Vec<(String, String)>,
+}
+
+/// Outcome of `Oui::lookup`, distinguishing a genuine vendor miss from an
+/// address that was never going to carry a registered OUI.
+#[derive(Debug, Clone, Copy)]
+pub enum LookupResult<'a> {
+    /// A vendor was found for this prefix.
+    Found(&'a Entry),
+    /// No vendor is registered for this prefix, but the address is
+    /// globally unique, so the lookup is meaningful - it just isn't in
+    /// the loaded database.
+    NotRegistered,
+    /// The address is locally administered, so an OUI-table lookup isn't
+    /// meaningful - this is typically a randomized/private client MAC.
+    LocallyAdministered(MacClass),
+}
+
+/// Classification of a MAC/EUI address derived from the first octet's I/G
+/// and U/L bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MacClass {
+    /// Globally unique (IEEE-assigned), unicast address.
+    GloballyUniqueUnicast,
+    /// Globally unique (IEEE-assigned), multicast address.
+    GloballyUniqueMulticast,
+    /// Locally administered, unicast address - commonly a randomized or
+    /// private client MAC that will never appear in the OUI table.
+    LocallyAdministeredUnicast,
+    /// Locally administered, multicast address.
+    LocallyAdministeredMulticast,
+}
+
+impl MacClass {
+    pub fn is_locally_administered(&self) -> bool {
+        //! `true` for the U/L (locally administered) variants.
+        matches!(
+            self,
+            MacClass::LocallyAdministeredUnicast | MacClass::LocallyAdministeredMulticast
+        )
+    }
+
+    pub fn is_multicast(&self) -> bool {
+        //! `true` for the I/G (multicast) variants.
+        matches!(
+            self,
+            MacClass::GloballyUniqueMulticast | MacClass::LocallyAdministeredMulticast
+        )
+    }
+}
+
+/// Inspects the I/G bit (multicast) and U/L bit (locally administered) of
+/// an address's first octet.
+fn classify_first_octet(first_octet: u8) -> MacClass {
+    let multicast = first_octet & 0b0000_0001 != 0;
+    let locally_administered = first_octet & 0b0000_0010 != 0;
+    match (locally_administered, multicast) {
+        (false, false) => MacClass::GloballyUniqueUnicast,
+        (false, true) => MacClass::GloballyUniqueMulticast,
+        (true, false) => MacClass::LocallyAdministeredUnicast,
+        (true, true) => MacClass::LocallyAdministeredMulticast,
+    }
+}
+
+/// Options controlling `Oui::search_manufacturers`.
+#[derive(Debug, Clone, Copy)]
+pub struct SearchOptions {
+    /// Maximum Levenshtein edit distance accepted for a fuzzy match once
+    /// substring matching fails to find a candidate.
+    pub max_distance: usize,
+    /// Maximum number of ranked matches to return.
+    pub limit: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            max_distance: 2,
+            limit: 10,
+        }
+    }
+}
+
+/// Lowercases and strips punctuation so that e.g. "Cisco Systems, Inc."
+/// and "cisco systems inc" compare equal.
+fn normalize_manufacturer(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 impl Oui {
@@ -66,17 +201,30 @@ impl Oui {
         //! ```
         let db_text = include_str!("../assets/oui.csv");
 
-        let oui_entry = read_into_db(db_text);
-        match oui_entry {
-            Ok(e) => Ok(Oui {
-                db: e.0,
-                manufacturer_map: e.1,
-                manufacturers: e.2,
-                ouis: e.3,
-                records: e.4,
-            }),
-            Err(e) => Err(format!("Error: {}", e)),
-        }
+        Self::from_reader(db_text.as_bytes(), Format::Csv)
+    }
+
+    #[cfg(feature = "with-precompiled-db")]
+    pub fn from_default_binary() -> Result<Oui, Error> {
+        //! Loads the precompiled binary database embedded at build time,
+        //! skipping the CSV re-parse `Oui::default` performs on every call.
+        //! Regenerate `assets/oui.bin` with the `compile_binary_db` example
+        //! whenever `assets/oui.csv` changes:
+        //!
+        //! ```sh
+        //! cargo run --example compile_binary_db
+        //! ```
+        //!
+        //! ## Example
+        //! ```rust
+        //! use mac_oui::Oui;
+        //!
+        //! let db = Oui::from_default_binary();
+        //! assert!(db.is_ok());
+        //! ```
+        let blob = include_bytes!("../assets/oui.bin");
+        bincode::deserialize(blob)
+            .map_err(|e| format!("could not deserialize precompiled database - {}", e))
     }
 
     pub fn from_csv_file<P: AsRef<Path>>(oui_csv: P) -> Result<Oui, Error> {
@@ -99,17 +247,44 @@ impl Oui {
                 oui_csv.as_ref().to_str().unwrap()
             ));
         };
-        let oui_entry = read_into_db(&db_text);
-        match oui_entry {
-            Ok(e) => Ok(Oui {
-                db: e.0,
-                manufacturer_map: e.1,
-                manufacturers: e.2,
-                ouis: e.3,
-                records: e.4,
-            }),
-            Err(e) => Err(format!("Error: {}", e)),
-        }
+        Self::from_reader(db_text.as_bytes(), Format::Csv)
+    }
+
+    pub fn from_reader<R: Read>(reader: R, format: Format) -> Result<Oui, Error> {
+        //! Loads a database from any `Read` source, dispatching on the given
+        //! payload `Format` (`Csv`, `Json` or `NdJson`).
+        //!
+        //! ## Example
+        //! ```rust
+        //! use mac_oui::{Format, Oui};
+        //!
+        //! let csv = std::fs::File::open("assets/oui.csv").unwrap();
+        //! let db = Oui::from_reader(csv, Format::Csv);
+        //! assert!(db.is_ok())
+        //! ```
+        let entries = parse_entries(reader, format)?;
+        let e = read_into_db(entries)?;
+        Ok(Oui {
+            db: e.0,
+            manufacturer_map: e.1,
+            manufacturers: e.2,
+            ouis: e.3,
+            records: e.4,
+            normalized_manufacturers: e.5,
+        })
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P, format: Format) -> Result<Oui, Error> {
+        //! Loads a database from the given path, dispatching on the given
+        //! payload `Format`. See [`Oui::from_reader`] for the non-CSV formats.
+        let file = File::open(path.as_ref()).map_err(|e| {
+            format!(
+                "could not open database file - {} ({})",
+                path.as_ref().to_str().unwrap_or(""),
+                e
+            )
+        })?;
+        Self::from_reader(file, format)
     }
 
     pub fn lookup_by_mac(&self, mac_addr: &str) -> Result<Option<&Entry>, Error> {
@@ -126,6 +301,126 @@ impl Oui {
         }
     }
 
+    pub fn to_binary<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        //! Serializes the parsed `db`, `manufacturer_map`, `manufacturers`,
+        //! `ouis`, `records` and `normalized_manufacturers` to a bincode
+        //! blob at the given path, so a
+        //! future `Oui::from_binary` call can load it directly without
+        //! re-parsing and re-indexing the source payload.
+        //!
+        //! ## Example
+        //! ```rust
+        //! use mac_oui::Oui;
+        //!
+        //! let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+        //! let path = std::env::temp_dir().join("mac_oui_doctest_to_binary.bin");
+        //! assert!(db.to_binary(&path).is_ok());
+        //! # std::fs::remove_file(&path).ok();
+        //! ```
+        let file = File::create(path.as_ref())
+            .map_err(|e| format!("could not create binary database file - {}", e))?;
+        bincode::serialize_into(file, self)
+            .map_err(|e| format!("could not serialize database to binary - {}", e))
+    }
+
+    pub fn from_binary<P: AsRef<Path>>(path: P) -> Result<Oui, Error> {
+        //! Loads a database previously written by `to_binary`, skipping the
+        //! CSV/JSON parse and range-map construction entirely.
+        //!
+        //! ## Example
+        //! ```rust
+        //! use mac_oui::Oui;
+        //!
+        //! let path = std::env::temp_dir().join("mac_oui_doctest_from_binary.bin");
+        //! Oui::from_csv_file("assets/oui.csv").unwrap().to_binary(&path).unwrap();
+        //!
+        //! let db = Oui::from_binary(&path);
+        //! assert!(db.is_ok());
+        //! # std::fs::remove_file(&path).ok();
+        //! ```
+        let file = File::open(path.as_ref())
+            .map_err(|e| format!("could not open binary database file - {}", e))?;
+        bincode::deserialize_from(file)
+            .map_err(|e| format!("could not deserialize binary database - {}", e))
+    }
+
+    pub fn lookup(&self, mac_addr: &str) -> Result<LookupResult, Error> {
+        //! Lookup for a Manufacturer Name accepting either a 48-bit MAC
+        //! address or a 64-bit EUI-64 identifier, dispatching on the parsed
+        //! width. For a bare 6-hex-digit OUI prefix, use `lookup_by_oui`.
+        //!
+        //! Unlike `lookup_by_mac`, a miss is further classified: a locally
+        //! administered address (e.g. a randomized/private client MAC) is
+        //! reported as `LookupResult::LocallyAdministered` rather than a
+        //! bare "not found", since such an address was never going to carry
+        //! a registered OUI.
+        let hex_len = mac_addr.chars().filter(|c| c.is_ascii_hexdigit()).count();
+        let entry = match hex_len {
+            12 => self.lookup_by_mac(mac_addr)?,
+            16 => {
+                let eui: MacAddr8 = match mac_addr.parse() {
+                    Ok(m) => m,
+                    Err(e) => return Err(e.to_string()),
+                };
+                self.query(&oui_bytes_to_u64(&eui.as_bytes()[..3]))?
+            }
+            _ => {
+                return Err(format!(
+                    "'{}' is neither a 48-bit MAC address nor a 64-bit EUI-64 identifier",
+                    mac_addr
+                ))
+            }
+        };
+        match entry {
+            Some(e) => Ok(LookupResult::Found(e)),
+            None => {
+                let class = self.classify(mac_addr)?;
+                if class.is_locally_administered() {
+                    Ok(LookupResult::LocallyAdministered(class))
+                } else {
+                    Ok(LookupResult::NotRegistered)
+                }
+            }
+        }
+    }
+
+    pub fn classify(&self, mac_addr: &str) -> Result<MacClass, Error> {
+        //! Classifies an address by the first octet's two low bits, without
+        //! touching the OUI table: unicast vs multicast (I/G bit) and
+        //! globally-unique vs locally-administered (U/L bit). Accepts the
+        //! same 48-bit MAC / 64-bit EUI-64 widths as `lookup`.
+        let hex_len = mac_addr.chars().filter(|c| c.is_ascii_hexdigit()).count();
+        let first_octet = match hex_len {
+            12 => {
+                let mac: MacAddress = mac_addr.parse().map_err(|e: macaddr::ParseError| e.to_string())?;
+                mac.as_bytes()[0]
+            }
+            16 => {
+                let eui: MacAddr8 = mac_addr.parse().map_err(|e: macaddr::ParseError| e.to_string())?;
+                eui.as_bytes()[0]
+            }
+            _ => {
+                return Err(format!(
+                    "'{}' is neither a 48-bit MAC address nor a 64-bit EUI-64 identifier",
+                    mac_addr
+                ))
+            }
+        };
+        Ok(classify_first_octet(first_octet))
+    }
+
+    pub fn lookup_by_oui(&self, oui: &str) -> Result<Option<&Entry>, Error> {
+        //! Lookup for a Manufacturer Name based upon a bare 6-hex-digit OUI
+        //! prefix (e.g. `70:B3:D5`), without a full MAC address.
+        let cleaned = oui.to_uppercase().replace([':', '-', '.'], "");
+        if cleaned.len() != 6 || !cleaned.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!("'{}' is not a valid 6-hex-digit OUI prefix", oui));
+        }
+        let oui_int = u64::from_str_radix(&cleaned, 16)
+            .map_err(|e| format!("could not parse OUI prefix '{}' - {}", oui, e))?;
+        self.query(&(oui_int << 24))
+    }
+
     pub fn lookup_by_manufacturer(
         &self,
         manufacturer_name: &str,
@@ -141,6 +436,51 @@ impl Oui {
         }
     }
 
+    pub fn search_manufacturers(
+        &self,
+        query: &str,
+        opts: SearchOptions,
+    ) -> Result<Vec<(&Entry, usize)>, Error> {
+        //! Case-insensitive, punctuation-tolerant manufacturer search.
+        //! Names containing the query score `0`; other names within
+        //! `opts.max_distance` Levenshtein edits of the query score
+        //! `distance + 1`, and results are ranked by ascending score. The
+        //! distance is taken against the whole normalized name, so a typo
+        //! like "cisco systms inc" still surfaces "Cisco Systems, Inc".
+        //! `opts.limit` bounds the number of `(Entry, score)` pairs
+        //! returned, not the number of distinct manufacturer names matched.
+        let normalized_query = normalize_manufacturer(query);
+        if normalized_query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut scored: Vec<(&str, usize)> = Vec::new();
+        for (normalized, original) in &self.normalized_manufacturers {
+            let score = if normalized.contains(&normalized_query) {
+                0
+            } else {
+                let distance = levenshtein(normalized, &normalized_query);
+                if distance > opts.max_distance {
+                    continue;
+                }
+                distance + 1
+            };
+            scored.push((original.as_str(), score));
+        }
+        scored.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+
+        let mut results = Vec::new();
+        for (name, score) in scored {
+            if let Some(entries) = self.manufacturer_map.get_vec(&name.to_string()) {
+                for entry in entries {
+                    results.push((entry, score));
+                }
+            }
+        }
+        results.truncate(opts.limit);
+        Ok(results)
+    }
+
     pub fn get_unique_manufacturers(&self) -> Result<Vec<String>, Error> {
         //! Get a list of Manufacturers present in the database
         Ok(Vec::from_iter(self.manufacturers.clone()))
@@ -165,6 +505,13 @@ impl Oui {
     }
 }
 
+/// Packs the 3-byte OUI prefix of an EUI-64 address into the same u64
+/// key space used by the range map (a MAC's OUI occupies the top 24 bits
+/// of its 48-bit, zero-padded-to-64-bit representation).
+fn oui_bytes_to_u64(oui_bytes: &[u8]) -> u64 {
+    ((oui_bytes[0] as u64) << 40) | ((oui_bytes[1] as u64) << 32) | ((oui_bytes[2] as u64) << 24)
+}
+
 trait MacAddrToU64 {
     fn to_u64(&self) -> Result<u64, Error>;
 }
@@ -198,32 +545,99 @@ impl MacAddrToU64 for MacAddress {
     }
 }
 
+/// Levenshtein edit distance between two strings, used to fuzzy-match
+/// manufacturer names that don't share a common substring.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, &ca) in a.iter().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev_diag
+            } else {
+                1 + prev_diag.min(row[j]).min(row[j + 1])
+            };
+            prev_diag = cur;
+        }
+    }
+    row[b.len()]
+}
+
 fn csv_de(csv_text: &str) -> Result<Vec<Entry>, csv::Error> {
     csv::Reader::from_reader(csv_text.as_bytes())
         .deserialize()
         .collect()
 }
 
-fn read_into_db(
-    csv_text: &str,
-) -> Result<(OuiMap, OuiMultiMap, HashSet<String>, HashSet<String>, i32), Error> {
-    //! Reads the OUI CSV File into a Btree Map
+/// Parses a payload of the given `Format` into a flat list of `Entry` values,
+/// so that `read_into_db` can build the OUI range-map/multimap the same way
+/// regardless of which format the caller fed in.
+fn parse_entries<R: Read>(mut reader: R, format: Format) -> Result<Vec<Entry>, Error> {
+    match format {
+        Format::Csv => {
+            let mut csv_text = String::new();
+            reader
+                .read_to_string(&mut csv_text)
+                .map_err(|e| format!("MalformedPayload (Csv): could not read payload - {}", e))?;
+            csv_de(&csv_text).map_err(|_e| {
+                String::from(
+                    "MalformedPayload (Csv): file is not matching OUI CSV, \
+                    be sure to download here: https://macaddress.io/database-download/csv",
+                )
+            })
+        }
+        Format::Json => serde_json::from_reader(reader).map_err(|e| {
+            format!(
+                "MalformedPayload (Json): expected a JSON array of Entry objects - {}",
+                e
+            )
+        }),
+        Format::NdJson => {
+            let mut entries = Vec::new();
+            for (nr, line) in BufReader::new(reader).lines().enumerate() {
+                let line = line.map_err(|e| {
+                    format!("MalformedPayload (NdJson): could not read line {} - {}", nr + 1, e)
+                })?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let entry: Entry = serde_json::from_str(&line).map_err(|e| {
+                    format!(
+                        "MalformedPayload (NdJson): line {} is not a valid Entry - {}",
+                        nr + 1,
+                        e
+                    )
+                })?;
+                entries.push(entry);
+            }
+            Ok(entries)
+        }
+    }
+}
+
+type ReadIntoDb = (
+    OuiMap,
+    OuiMultiMap,
+    HashSet<String>,
+    HashSet<String>,
+    i32,
+    Vec<(String, String)>,
+);
+
+fn read_into_db(records: impl IntoIterator<Item = Entry>) -> Result<ReadIntoDb, Error> {
+    //! Builds the OUI range-map/multimap from an iterator of already
+    //! deserialized `Entry` values, shared by all three payload formats.
     let mut oui_db = OuiMap::new();
     let mut manufacturer_map = OuiMultiMap::new();
     let mut unique_manufacturers = HashSet::<String>::new();
     let mut unique_ouis = HashSet::<String>::new();
     let mut nr_records = 0;
 
-    let records = match csv_de(csv_text) {
-        Ok(r) => r,
-        Err(_e) => {
-            return Err(String::from(
-                "CSV file is not matching OUI CSV, \
-            be sure to download here: https://macaddress.io/database-download/csv",
-            ))
-        }
-    };
-
     // Loop thru
     for record in records {
         // Get the mask if any
@@ -281,12 +695,18 @@ fn read_into_db(
         unique_ouis.insert(record.oui);
     }
 
+    let normalized_manufacturers = unique_manufacturers
+        .iter()
+        .map(|name| (normalize_manufacturer(name), name.clone()))
+        .collect();
+
     Ok((
         oui_db,
         manufacturer_map,
         unique_manufacturers,
         unique_ouis,
         nr_records,
+        normalized_manufacturers,
     ))
 }
 
@@ -301,6 +721,13 @@ mod tests {
         assert!(db.is_ok());
     }
 
+    #[cfg(feature = "with-precompiled-db")]
+    #[test]
+    fn test_from_default_binary() {
+        let db = Oui::from_default_binary();
+        assert!(db.is_ok());
+    }
+
     #[test]
     fn test_from_file() {
         let db = Oui::from_csv_file("assets/oui.csv");
@@ -315,6 +742,130 @@ mod tests {
         assert_eq!(res.unwrap().company_name, "Ieee Registration Authority")
     }
 
+    #[test]
+    fn test_from_reader_json() {
+        let json = r#"[{"oui":"AA:BB:CC","isPrivate":"0","companyName":"Test Json Co","companyAddress":"Somewhere","countryCode":"US","assignmentBlockSize":"MA-L","dateCreated":"2020-01-01","dateUpdated":"2020-01-01"}]"#;
+        let db = Oui::from_reader(json.as_bytes(), Format::Json).unwrap();
+        assert_eq!(db.get_total_records(), 1);
+        assert_eq!(
+            db.lookup_by_mac("AA:BB:CC:00:00:01")
+                .unwrap()
+                .unwrap()
+                .company_name,
+            "Test Json Co"
+        );
+    }
+
+    #[test]
+    fn test_from_reader_ndjson() {
+        let ndjson = "{\"oui\":\"11:22:33\",\"isPrivate\":\"0\",\"companyName\":\"Test NdJson Co\",\"companyAddress\":\"Somewhere\",\"countryCode\":\"US\",\"assignmentBlockSize\":\"MA-L\",\"dateCreated\":\"2020-01-01\",\"dateUpdated\":\"2020-01-01\"}\n";
+        let db = Oui::from_reader(ndjson.as_bytes(), Format::NdJson).unwrap();
+        assert_eq!(db.get_total_records(), 1);
+    }
+
+    #[test]
+    fn test_from_reader_malformed_json() {
+        let res = Oui::from_reader("not json".as_bytes(), Format::Json);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_lookup_eui64() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+
+        match db.lookup("70:B3:D5:FF:e7:4f:81:00").unwrap() {
+            LookupResult::Found(entry) => {
+                assert_eq!(entry.company_name, "Ieee Registration Authority")
+            }
+            other => panic!("expected a Found result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_and_lookup_locally_administered() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+
+        assert_eq!(
+            db.classify("02:00:00:12:34:56").unwrap(),
+            MacClass::LocallyAdministeredUnicast
+        );
+        match db.lookup("02:00:00:12:34:56").unwrap() {
+            LookupResult::LocallyAdministered(class) => {
+                assert!(class.is_locally_administered())
+            }
+            other => panic!("expected a LocallyAdministered result, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_classify_globally_unique_unicast() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+
+        let class = db.classify("70:B3:D5:e7:4f:81").unwrap();
+        assert_eq!(class, MacClass::GloballyUniqueUnicast);
+        assert!(!class.is_locally_administered());
+        assert!(!class.is_multicast());
+    }
+
+    #[test]
+    fn test_lookup_by_oui() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+
+        let res = db.lookup_by_oui("70:B3:D5").unwrap();
+        assert_eq!(res.unwrap().company_name, "Ieee Registration Authority")
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+        let bin_path = std::env::temp_dir().join("mac_oui_test_binary_roundtrip.bin");
+
+        db.to_binary(&bin_path).unwrap();
+        let restored = Oui::from_binary(&bin_path).unwrap();
+
+        assert_eq!(restored.get_total_records(), db.get_total_records());
+        assert_eq!(
+            restored
+                .lookup_by_mac("70:B3:D5:e7:4f:81")
+                .unwrap()
+                .unwrap()
+                .company_name,
+            "Ieee Registration Authority"
+        );
+
+        let _ = std::fs::remove_file(&bin_path);
+    }
+
+    #[test]
+    fn test_search_manufacturers_substring() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+
+        let res = db
+            .search_manufacturers("ieee registration", SearchOptions::default())
+            .unwrap();
+        assert!(res
+            .iter()
+            .any(|(e, score)| e.company_name == "Ieee Registration Authority" && *score == 0));
+    }
+
+    #[test]
+    fn test_search_manufacturers_fuzzy() {
+        let db = Oui::from_csv_file("assets/oui.csv").unwrap();
+
+        let res = db
+            .search_manufacturers(
+                "ieee registraton authorty",
+                SearchOptions {
+                    max_distance: 3,
+                    limit: 5,
+                },
+            )
+            .unwrap();
+        assert!(res
+            .iter()
+            .any(|(e, _)| e.company_name == "Ieee Registration Authority"));
+    }
+
     #[test]
     fn test_get_by_manufacturer() {
         let db = Oui::from_csv_file("assets/oui.csv").unwrap();